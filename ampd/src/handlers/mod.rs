@@ -0,0 +1,6 @@
+pub mod errors;
+pub mod mvx_verify_msg;
+pub mod mvx_verify_verifier_set;
+
+#[cfg(test)]
+pub(crate) mod tests;
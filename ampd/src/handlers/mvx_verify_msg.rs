@@ -10,6 +10,7 @@ use serde::Deserialize;
 use tokio::sync::watch::Receiver;
 use tracing::info;
 
+use axelar_wasm_std::msg_id::HexTxHashEventIndex;
 use axelar_wasm_std::voting::{PollId, Vote};
 use events::Error::EventTypeMismatch;
 use events::Event;
@@ -18,6 +19,7 @@ use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
 use crate::handlers::errors::Error;
+use crate::mvx::deserialize_hash;
 use crate::mvx::proxy::MvxProxy;
 use crate::mvx::verifier::verify_message;
 use crate::types::{Hash, TMAddress};
@@ -26,14 +28,27 @@ type Result<T> = error_stack::Result<T, Error>;
 
 #[derive(Deserialize, Debug)]
 pub struct Message {
+    #[serde(deserialize_with = "deserialize_hash")]
     pub tx_id: Hash,
-    pub event_index: u32,
+    pub event_index: u64,
     pub destination_address: String,
     pub destination_chain: router_api::ChainName,
     pub source_address: Address,
+    #[serde(deserialize_with = "deserialize_hash")]
     pub payload_hash: Hash,
 }
 
+impl Message {
+    /// The canonical message ID the voting verifier correlates this message by,
+    /// combining the transaction hash and the index of the event within it.
+    pub fn message_id(&self) -> HexTxHashEventIndex {
+        HexTxHashEventIndex {
+            tx_hash: self.tx_id.to_fixed_bytes(),
+            event_index: self.event_index,
+        }
+    }
+}
+
 #[derive(Deserialize, Debug)]
 #[try_from("wasm-messages_poll_started")]
 struct PollStartedEvent {
@@ -133,11 +148,20 @@ where
         let votes: Vec<Vote> = messages
             .iter()
             .map(|msg| {
-                transactions_info
+                let vote = transactions_info
                     .get(&msg.tx_id)
                     .map_or(Vote::NotFound, |transaction| {
                         verify_message(&source_gateway_address, transaction, msg)
-                    })
+                    });
+
+                info!(
+                    poll_id = poll_id.to_string(),
+                    message_id = msg.message_id().to_string(),
+                    vote = ?vote,
+                    "ready to vote for message"
+                );
+
+                vote
             })
             .collect();
 
@@ -196,7 +220,7 @@ mod tests {
             message.tx_id.encode_hex::<String>()
                 == "dfaf64de66510723f2efbacd7ead3c4f8c856aed1afc2cb30254552aeda47312",
         );
-        assert!(message.event_index == 1u32);
+        assert!(message.event_index == 1u64);
         assert!(message.destination_chain.to_string() == "ethereum");
         assert!(
             message.source_address.to_bech32_string().unwrap()
@@ -204,6 +228,36 @@ mod tests {
         );
     }
 
+    #[derive(serde::Deserialize)]
+    struct HashWrapper(#[serde(deserialize_with = "super::deserialize_hash")] Hash);
+
+    #[test]
+    fn should_deserialize_hash_with_0x_prefix() {
+        let hash = "dfaf64de66510723f2efbacd7ead3c4f8c856aed1afc2cb30254552aeda47312";
+
+        let with_prefix: HashWrapper = serde_json::from_str(&format!("\"0x{hash}\"")).unwrap();
+        let without_prefix: HashWrapper = serde_json::from_str(&format!("\"{hash}\"")).unwrap();
+
+        assert_eq!(with_prefix.0, without_prefix.0);
+        assert_eq!(with_prefix.0.encode_hex::<String>(), hash);
+    }
+
+    #[test]
+    fn should_reject_hash_with_invalid_hex() {
+        let result: std::result::Result<HashWrapper, _> = serde_json::from_str(
+            "\"0xnot-valid-hex-data-------------------------------------------\"",
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_reject_hash_with_wrong_length() {
+        let result: std::result::Result<HashWrapper, _> = serde_json::from_str("\"0xdfaf\"");
+
+        assert!(result.is_err());
+    }
+
     // Should not handle event if it is not a poll started event
     #[async_test]
     async fn not_poll_started_event() {
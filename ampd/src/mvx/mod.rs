@@ -0,0 +1,25 @@
+pub mod proxy;
+pub mod verifier;
+
+use serde::Deserialize;
+
+use crate::types::Hash;
+
+/// Deserializes a 32 byte hash from a hex string, with or without a leading `0x` prefix.
+pub(crate) fn deserialize_hash<'de, D>(deserializer: D) -> Result<Hash, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let hex_str = String::deserialize(deserializer)?;
+    let stripped = hex_str.strip_prefix("0x").unwrap_or(&hex_str);
+
+    if stripped.len() != 64 || !stripped.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(serde::de::Error::custom(format!(
+            "invalid 32 byte hex hash: {hex_str}"
+        )));
+    }
+
+    let bytes = hex::decode(stripped).map_err(serde::de::Error::custom)?;
+
+    Ok(Hash::from_slice(&bytes))
+}
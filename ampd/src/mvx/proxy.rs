@@ -0,0 +1,54 @@
+use std::collections::{HashMap, HashSet};
+
+use async_trait::async_trait;
+use error_stack::ResultExt;
+use hex::ToHex;
+use mockall::automock;
+use multiversx_sdk::data::transaction::TransactionOnNetwork;
+use multiversx_sdk::gateway::GatewayProxy;
+
+use crate::handlers::errors::Error;
+use crate::types::Hash;
+
+type Result<T> = error_stack::Result<T, Error>;
+
+#[automock]
+#[async_trait]
+pub trait MvxProxy {
+    async fn transactions_info_with_results(
+        &self,
+        tx_hashes: HashSet<Hash>,
+    ) -> Result<HashMap<Hash, TransactionOnNetwork>>;
+}
+
+pub struct MvxProxyClient {
+    gateway: GatewayProxy,
+}
+
+impl MvxProxyClient {
+    pub fn new(gateway: GatewayProxy) -> Self {
+        Self { gateway }
+    }
+}
+
+#[async_trait]
+impl MvxProxy for MvxProxyClient {
+    async fn transactions_info_with_results(
+        &self,
+        tx_hashes: HashSet<Hash>,
+    ) -> Result<HashMap<Hash, TransactionOnNetwork>> {
+        let mut transactions_info = HashMap::new();
+
+        for tx_hash in tx_hashes {
+            let transaction = self
+                .gateway
+                .get_transaction_info_with_results(&tx_hash.encode_hex::<String>())
+                .await
+                .change_context(Error::TxReceipts)?;
+
+            transactions_info.insert(tx_hash, transaction);
+        }
+
+        Ok(transactions_info)
+    }
+}
@@ -0,0 +1,266 @@
+use axelar_wasm_std::voting::Vote;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use multisig::verifier_set::VerifierSet;
+use multiversx_sdk::data::address::Address;
+use multiversx_sdk::data::transaction::{Events, TransactionOnNetwork};
+
+use crate::handlers::mvx_verify_msg::Message;
+use crate::handlers::mvx_verify_verifier_set::VerifierSetConfirmation;
+
+const MVX_CONTRACT_CALL_EVENT: &str = "callContract";
+const MVX_SIGNERS_ROTATED_EVENT: &str = "signersRotated";
+
+fn event_at_index(transaction: &TransactionOnNetwork, event_index: u64) -> Option<&Events> {
+    let index: usize = event_index.try_into().ok()?;
+
+    transaction.logs.as_ref()?.events.get(index)
+}
+
+fn decode_topic(event: &Events, index: usize) -> Option<Vec<u8>> {
+    let topic = event.topics.as_ref()?.get(index)?;
+
+    STANDARD.decode(topic).ok()
+}
+
+fn is_valid_contract_call_event(
+    event: &Events,
+    source_gateway_address: &Address,
+    message: &Message,
+) -> bool {
+    if &event.address != source_gateway_address || event.identifier != MVX_CONTRACT_CALL_EVENT {
+        return false;
+    }
+
+    let Some(destination_chain) = decode_topic(event, 1) else {
+        return false;
+    };
+    let Some(destination_address) = decode_topic(event, 2) else {
+        return false;
+    };
+    let Some(payload_hash) = decode_topic(event, 3) else {
+        return false;
+    };
+
+    destination_chain == message.destination_chain.to_string().as_bytes()
+        && destination_address == message.destination_address.as_bytes()
+        && payload_hash == message.payload_hash.as_bytes()
+}
+
+pub fn verify_message(
+    source_gateway_address: &Address,
+    transaction: &TransactionOnNetwork,
+    message: &Message,
+) -> Vote {
+    if !transaction.is_completed() {
+        return Vote::NotFound;
+    }
+
+    match event_at_index(transaction, message.event_index) {
+        Some(event) if is_valid_contract_call_event(event, source_gateway_address, message) => {
+            Vote::SucceededOnChain
+        }
+        _ => Vote::NotFound,
+    }
+}
+
+/// Strips leading zero bytes so fixed-width big-endian integers (e.g. `Uint128::to_be_bytes`)
+/// compare equal to the compact big-endian encoding MultiversX emits on-chain for `BigUint`
+/// values.
+fn trim_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let first_nonzero = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+
+    &bytes[first_nonzero..]
+}
+
+/// The weighted signers that make up a verifier set, as emitted on-chain by the gateway's
+/// signer-rotation event: a sorted list of (public key, weight) pairs plus the threshold
+/// required to reach quorum.
+fn weighted_signers(verifier_set: &VerifierSet) -> (Vec<(Vec<u8>, Vec<u8>)>, Vec<u8>) {
+    let mut signers: Vec<(Vec<u8>, Vec<u8>)> = verifier_set
+        .signers
+        .values()
+        .map(|signer| {
+            (
+                signer.pub_key.as_ref().to_vec(),
+                trim_leading_zeros(&signer.weight.to_be_bytes()).to_vec(),
+            )
+        })
+        .collect();
+    signers.sort();
+
+    (
+        signers,
+        trim_leading_zeros(&verifier_set.threshold.to_be_bytes()).to_vec(),
+    )
+}
+
+/// Length of the `u32` big-endian prefix MultiversX's nested encoding uses ahead of every
+/// dynamic-length field (the same convention `ManagedVec`/`VecMapper` serialization follows for
+/// any contract-emitted event data), e.g. `signersRotated`'s `new_signers: Vec<(PubKey, BigUint)>`
+/// data payload below.
+const LEN_PREFIX_BYTES: usize = 4;
+
+/// Decodes the `signersRotated` event's new signer set: `topics[1]` carries the new threshold
+/// (a `BigUint`, so compact big-endian with no fixed width), and `data` is the nested-encoded
+/// `Vec<(pub_key, weight)>`, each element length-prefixed per MultiversX's nested encoding.
+fn decode_new_signers(event: &Events) -> Option<(Vec<(Vec<u8>, Vec<u8>)>, Vec<u8>)> {
+    let threshold = decode_topic(event, 1)?;
+    let data = STANDARD.decode(event.data.as_ref()?).ok()?;
+
+    let mut signers = Vec::new();
+    let mut remaining = data.as_slice();
+    while !remaining.is_empty() {
+        if remaining.len() < 2 * LEN_PREFIX_BYTES {
+            return None;
+        }
+
+        let (pub_key_len_bytes, rest) = remaining.split_at(LEN_PREFIX_BYTES);
+        let pub_key_len = u32::from_be_bytes(pub_key_len_bytes.try_into().ok()?) as usize;
+
+        if rest.len() < pub_key_len + LEN_PREFIX_BYTES {
+            return None;
+        }
+        let (pub_key, rest) = rest.split_at(pub_key_len);
+        let (weight_len_bytes, rest) = rest.split_at(LEN_PREFIX_BYTES);
+        let weight_len = u32::from_be_bytes(weight_len_bytes.try_into().ok()?) as usize;
+
+        if rest.len() < weight_len {
+            return None;
+        }
+        let (weight, rest) = rest.split_at(weight_len);
+
+        signers.push((pub_key.to_vec(), weight.to_vec()));
+        remaining = rest;
+    }
+    signers.sort();
+
+    Some((signers, threshold))
+}
+
+fn is_valid_verifier_set_event(
+    event: &Events,
+    source_gateway_address: &Address,
+    verifier_set: &VerifierSet,
+) -> bool {
+    if &event.address != source_gateway_address || event.identifier != MVX_SIGNERS_ROTATED_EVENT {
+        return false;
+    }
+
+    decode_new_signers(event) == Some(weighted_signers(verifier_set))
+}
+
+pub fn verify_verifier_set(
+    source_gateway_address: &Address,
+    transaction: &TransactionOnNetwork,
+    confirmation: &VerifierSetConfirmation,
+) -> Vote {
+    if !transaction.is_completed() {
+        return Vote::NotFound;
+    }
+
+    match event_at_index(transaction, confirmation.event_index) {
+        Some(event)
+            if is_valid_verifier_set_event(
+                event,
+                source_gateway_address,
+                &confirmation.verifier_set,
+            ) =>
+        {
+            Vote::SucceededOnChain
+        }
+        _ => Vote::NotFound,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use cosmwasm_std::{Addr, HexBinary, Uint256};
+    use multisig::key::PublicKey;
+    use multisig::verifier_set::Signer;
+    use multiversx_sdk::data::transaction::Events;
+
+    use super::*;
+
+    fn gateway_address() -> Address {
+        Address::from_bech32_string(
+            "erd1qqqqqqqqqqqqqpgqsvzyz88e8v8j6x3wquatxuztnxjwnw92kkls6rdtzx",
+        )
+        .unwrap()
+    }
+
+    fn verifier_set_with(pub_key: Vec<u8>, weight: u128, threshold: u128) -> VerifierSet {
+        let signer = Signer {
+            address: Addr::unchecked("verifier"),
+            weight: Uint256::from(weight),
+            pub_key: PublicKey::Ed25519(HexBinary::from(pub_key)),
+        };
+
+        let mut signers = BTreeMap::new();
+        signers.insert("verifier".to_string(), signer);
+
+        VerifierSet {
+            signers,
+            threshold: Uint256::from(threshold),
+            created_at: 0,
+        }
+    }
+
+    // MultiversX emits BigUint values in their compact, non-zero-padded big-endian form, unlike
+    // the fixed 32 byte big-endian encoding `Uint256::to_be_bytes` produces for the expected
+    // verifier set. A correct rotation must still compare equal.
+    //
+    // `data` below is built using the same `[len: u32][bytes]` nested-encoding layout
+    // `decode_new_signers` assumes MultiversX's gateway contract emits; see that function's doc
+    // comment for why.
+    fn rotation_event(signers: &[(Vec<u8>, Vec<u8>)], threshold: &[u8]) -> Events {
+        let mut data = Vec::new();
+        for (pub_key, weight) in signers {
+            data.extend_from_slice(&(pub_key.len() as u32).to_be_bytes());
+            data.extend_from_slice(pub_key);
+            data.extend_from_slice(&(weight.len() as u32).to_be_bytes());
+            data.extend_from_slice(weight);
+        }
+
+        Events {
+            address: gateway_address(),
+            identifier: MVX_SIGNERS_ROTATED_EVENT.to_string(),
+            topics: Some(vec![
+                STANDARD.encode(MVX_SIGNERS_ROTATED_EVENT),
+                STANDARD.encode(threshold),
+            ]),
+            data: Some(STANDARD.encode(data)),
+        }
+    }
+
+    #[test]
+    fn should_match_compact_on_chain_weights_against_fixed_width_expected_weights() {
+        let pub_key = vec![1u8; 32];
+        let verifier_set = verifier_set_with(pub_key.clone(), 5, 5);
+
+        // on-chain compact encoding of `5`, with no leading zero padding
+        let event = rotation_event(&[(pub_key, vec![5u8])], &[5u8]);
+
+        assert!(is_valid_verifier_set_event(
+            &event,
+            &gateway_address(),
+            &verifier_set
+        ));
+    }
+
+    #[test]
+    fn should_reject_mismatched_weight() {
+        let pub_key = vec![1u8; 32];
+        let verifier_set = verifier_set_with(pub_key.clone(), 5, 5);
+
+        let event = rotation_event(&[(pub_key, vec![6u8])], &[5u8]);
+
+        assert!(!is_valid_verifier_set_event(
+            &event,
+            &gateway_address(),
+            &verifier_set
+        ));
+    }
+}
@@ -0,0 +1,59 @@
+use cosmwasm_std::HexBinary;
+use multisig::msg::SignerWithSig;
+use multisig::verifier_set::VerifierSet;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::payload::Payload;
+
+/// The BCS encoding of a gateway `execute_data` call: the verifier set and signatures that
+/// authorize it, alongside the payload they sign over.
+#[derive(Serialize, Deserialize)]
+struct ExecuteData {
+    verifier_set: VerifierSet,
+    sigs: Vec<SignerWithSig>,
+    payload: Payload,
+}
+
+/// Encodes the verifier set, signatures and payload using BCS (Binary Canonical Serialization),
+/// the format used by Sui/Aptos-style gateways.
+pub fn encode_execute_data(
+    verifier_set: &VerifierSet,
+    sigs: Vec<SignerWithSig>,
+    payload: &Payload,
+) -> Result<HexBinary, ContractError> {
+    let execute_data = ExecuteData {
+        verifier_set: verifier_set.clone(),
+        sigs,
+        payload: payload.clone(),
+    };
+
+    let encoded = bcs::to_bytes(&execute_data).map_err(|_| ContractError::InvalidPayload)?;
+
+    Ok(HexBinary::from(encoded))
+}
+
+/// Decodes the payload out of a BCS-encoded `execute_data` blob, so it can be hashed and
+/// compared against the payload a verifier confirmed for this message.
+pub fn decode_payload(payload: &HexBinary) -> Result<Payload, ContractError> {
+    let execute_data: ExecuteData =
+        bcs::from_bytes(payload.as_slice()).map_err(|_| ContractError::InvalidPayload)?;
+
+    Ok(execute_data.payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::test_utils::{payload, verifier_set};
+
+    #[test]
+    fn encode_then_decode_payload_round_trips() {
+        let payload = payload();
+
+        let encoded = encode_execute_data(&verifier_set(), vec![], &payload).unwrap();
+        let decoded = decode_payload(&encoded).unwrap();
+
+        assert_eq!(payload, decoded);
+    }
+}
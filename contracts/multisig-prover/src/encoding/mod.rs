@@ -1,12 +1,71 @@
 pub mod abi;
+pub mod bcs;
 pub mod mvx;
 
+#[cfg(test)]
+pub(crate) mod test_utils;
+
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::HexBinary;
+use multisig::msg::SignerWithSig;
+use multisig::verifier_set::VerifierSet;
+
+use crate::error::ContractError;
+use crate::payload::Payload;
 
 #[cw_serde]
 #[derive(Copy)]
 pub enum Encoder {
     Abi,
     Bcs,
-    Mvx
+    Mvx,
+}
+
+impl Encoder {
+    /// Encodes the verifier set, collected signatures and payload into the gateway's
+    /// `execute_data` calldata, in whichever format the source chain's gateway expects.
+    pub fn encode_execute_data(
+        &self,
+        verifier_set: &VerifierSet,
+        sigs: Vec<SignerWithSig>,
+        payload: &Payload,
+    ) -> Result<HexBinary, ContractError> {
+        match self {
+            Encoder::Abi => abi::encode_execute_data(verifier_set, sigs, payload),
+            Encoder::Bcs => bcs::encode_execute_data(verifier_set, sigs, payload),
+            Encoder::Mvx => mvx::encode_execute_data(verifier_set, sigs, payload),
+        }
+    }
+
+    /// Decodes a command payload that was encoded by the source chain's gateway, so it can be
+    /// hashed and compared against the payload reported by a verifier.
+    pub fn decode_payload(&self, payload: &HexBinary) -> Result<Payload, ContractError> {
+        match self {
+            Encoder::Abi => abi::decode_payload(payload),
+            Encoder::Bcs => bcs::decode_payload(payload),
+            Encoder::Mvx => mvx::decode_payload(payload),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_utils::{payload, verifier_set};
+    use super::*;
+
+    #[test]
+    fn each_encoder_round_trips_the_payload_it_encoded() {
+        for encoder in [Encoder::Abi, Encoder::Bcs, Encoder::Mvx] {
+            let payload = payload();
+
+            let encoded = encoder
+                .encode_execute_data(&verifier_set(), vec![], &payload)
+                .unwrap_or_else(|_| panic!("{encoder:?} should encode execute data"));
+            let decoded = encoder
+                .decode_payload(&encoded)
+                .unwrap_or_else(|_| panic!("{encoder:?} should decode its own execute data"));
+
+            assert_eq!(payload, decoded, "{encoder:?} did not round trip");
+        }
+    }
 }
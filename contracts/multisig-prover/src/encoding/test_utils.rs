@@ -0,0 +1,24 @@
+use multisig::verifier_set::VerifierSet;
+use router_api::{CrossChainId, Message};
+
+use crate::payload::Payload;
+
+pub fn payload() -> Payload {
+    Payload::Messages(vec![Message {
+        cc_id: CrossChainId::new("multiversx", "hash-0").unwrap(),
+        source_address: "erd1qqqqqqqqqqqqqpgqsvzyz88e8v8j6x3wquatxuztnxjwnw92kkls6rdtzx"
+            .parse()
+            .unwrap(),
+        destination_chain: "ethereum".parse().unwrap(),
+        destination_address: "0x0000000000000000000000000000000000dead".parse().unwrap(),
+        payload_hash: [0; 32],
+    }])
+}
+
+pub fn verifier_set() -> VerifierSet {
+    VerifierSet {
+        signers: Default::default(),
+        threshold: 1u64.into(),
+        created_at: 0,
+    }
+}
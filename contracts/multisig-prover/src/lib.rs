@@ -0,0 +1,4 @@
+pub mod encoding;
+pub mod error;
+pub mod payload;
+pub mod verifier;
@@ -0,0 +1,46 @@
+use cosmwasm_std::HexBinary;
+
+use crate::encoding::Encoder;
+use crate::error::ContractError;
+use crate::payload::Payload;
+
+/// Confirms that `encoded_payload`, as reported by a verifier for the gateway transaction it
+/// observed, decodes (in the source chain's encoding) to the `payload` this contract is about
+/// to build a proof for. Returns `ContractError::InvalidPayload` when the decoded payload
+/// differs from `payload`.
+pub fn verify_payload(
+    encoder: Encoder,
+    payload: &Payload,
+    encoded_payload: &HexBinary,
+) -> Result<(), ContractError> {
+    let decoded = encoder.decode_payload(encoded_payload)?;
+
+    if &decoded != payload {
+        return Err(ContractError::InvalidPayload);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::bcs;
+    use crate::encoding::test_utils::{payload, verifier_set};
+
+    #[test]
+    fn accepts_payload_that_matches_the_encoded_one() {
+        let payload = payload();
+        let encoded = bcs::encode_execute_data(&verifier_set(), vec![], &payload).unwrap();
+
+        assert!(verify_payload(Encoder::Bcs, &payload, &encoded).is_ok());
+    }
+
+    #[test]
+    fn rejects_payload_that_does_not_match_the_encoded_one() {
+        let encoded = bcs::encode_execute_data(&verifier_set(), vec![], &payload()).unwrap();
+        let other_payload = Payload::Messages(vec![]);
+
+        assert!(verify_payload(Encoder::Bcs, &other_payload, &encoded).is_err());
+    }
+}